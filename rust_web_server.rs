@@ -1,7 +1,17 @@
 Here is a complete example of a production-ready Rust web server using Actix-web framework.
 
 ```rust
-use actix_web::{web, App, HttpServer, Responder, HttpResponse};
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::error::{InternalError, PathError};
+use actix_web::http::header;
+use actix_web::middleware::Next;
+use actix_web::{middleware, web, App, Error, HttpRequest, HttpServer, Responder, HttpResponse};
+use std::future::Future;
+use std::os::unix::io::FromRawFd;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::{env, io};
 
 #[actix_web::main]
@@ -15,26 +25,266 @@ async fn main() -> io::Result<()> {
     // Setup logger
     env_logger::init();
 
-    // Create actix-web server
-    let server = HttpServer::new(|| {
+    // Graceful shutdown timeout (seconds), configurable for hot-deploy supervisors
+    let shutdown_timeout: u64 = env::var("SHUTDOWN_TIMEOUT")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse()
+        .expect("SHUTDOWN_TIMEOUT must be a number");
+
+    // Access-log format is left to the operator; Actix's default is close to Common Log Format
+    let access_log_format = env::var("ACCESS_LOG_FORMAT").ok();
+    let enable_compression = env::var("ENABLE_COMPRESSION")
+        .map(|v| v != "0" && v.to_lowercase() != "false")
+        .unwrap_or(true);
+    let version_header = env::var("APP_VERSION").unwrap_or_else(|_| "dev".to_string());
+
+    // Readiness probes other parts of the SDK can register at startup; liveness needs none.
+    let mut readiness = ReadinessRegistry::new();
+    readiness.register("self", || Box::pin(async { Ok(()) }));
+    let readiness = web::Data::new(Arc::new(readiness));
+
+    // Assigns each accepted TCP connection a unique ID, stashed in its extensions map so every
+    // request handled on that connection can read it back out via `conn_data::<ConnectionId>()`.
+    let next_connection_id = Arc::new(AtomicU64::new(1));
+    let on_connect = move |_conn: &dyn std::any::Any, ext: &mut actix_web::dev::Extensions| {
+        let id = next_connection_id.fetch_add(1, Ordering::Relaxed);
+        ext.insert(ConnectionId(id));
+    };
+
+    let app_factory = move || {
+        let logger = match &access_log_format {
+            Some(fmt) => middleware::Logger::new(fmt),
+            None => middleware::Logger::default(),
+        };
+
         App::new()
+            .app_data(readiness.clone())
+            .app_data(web::PathConfig::default().error_handler(path_error_handler))
+            .wrap(logger)
+            .wrap(middleware::Condition::new(
+                enable_compression,
+                middleware::Compress::default(),
+            ))
+            .wrap(middleware::DefaultHeaders::new().add((header::HeaderName::from_static("x-version"), version_header.clone())))
+            .wrap(middleware::from_fn(connection_id_header))
             .route("/", web::get().to(index))
-    })
-    .bind(("0.0.0.0", port))?
-    .run();
+            .route("/users/{user_id}", web::get().to(get_user))
+            .route("/health/live", web::get().to(health_live))
+            .route("/health/ready", web::get().to(health_ready))
+            .default_service(web::route().to(not_found))
+    };
+
+    // Create actix-web server, adopting listeners inherited from Server::Starter if present
+    let server = match server_starter_listeners()? {
+        Some(listeners) => {
+            let mut server = HttpServer::new(app_factory)
+                .shutdown_timeout(shutdown_timeout)
+                .on_connect(on_connect);
+            for listener in listeners {
+                server = match listener {
+                    InheritedListener::Tcp(listener) => {
+                        println!("Server adopted inherited TCP listener {:?}", listener.local_addr()?);
+                        server.listen(listener)?
+                    }
+                    InheritedListener::Unix(listener) => {
+                        println!("Server adopted inherited Unix listener {:?}", listener.local_addr()?);
+                        server.listen_uds(listener)?
+                    }
+                };
+            }
+            server.run()
+        }
+        None => {
+            let server = HttpServer::new(app_factory)
+                .shutdown_timeout(shutdown_timeout)
+                .on_connect(on_connect)
+                .bind(("0.0.0.0", port))?
+                .run();
+            println!("Server running at http://0.0.0.0:{}", port);
+            server
+        }
+    };
 
-    // Run the server
-    println!("Server running at http://0.0.0.0:{}", port);
     server.await
 }
 
+/// A listening socket inherited from a Server::Starter-style supervisor, reconstructed from a
+/// raw file descriptor. TCP and Unix domain sockets need different `HttpServer` plumbing
+/// (`listen` vs `listen_uds`), so callers match on this instead of assuming one socket family.
+enum InheritedListener {
+    Tcp(std::net::TcpListener),
+    Unix(std::os::unix::net::UnixListener),
+}
+
+/// Parses `SERVER_STARTER_PORT` (set by a Server::Starter-style supervisor) and reconstructs
+/// the listening sockets it hands down, so a parent process can swap this binary out without
+/// ever closing the accept socket. Format is a semicolon-separated list of `host:port=fd` (TCP)
+/// or `path=fd` (Unix domain socket) entries; returns `None` when the variable is absent so the
+/// caller falls back to a normal `bind`.
+fn server_starter_listeners() -> io::Result<Option<Vec<InheritedListener>>> {
+    let raw = match env::var("SERVER_STARTER_PORT") {
+        Ok(raw) => raw,
+        Err(_) => return Ok(None),
+    };
+
+    let mut listeners = Vec::new();
+    for entry in raw.split(';').filter(|e| !e.is_empty()) {
+        let (addr, fd_str) = entry
+            .rsplit_once('=')
+            .expect("SERVER_STARTER_PORT entry must be in `addr=fd` form");
+        let fd: i32 = fd_str
+            .parse()
+            .expect("SERVER_STARTER_PORT fd must be an integer");
+
+        // An entry whose address parses as `host:port` is a TCP socket; anything else (a
+        // filesystem path) is a Unix domain socket, matching Server::Starter's own convention.
+        let listener = if addr.parse::<std::net::SocketAddr>().is_ok() {
+            // Safety: the fd was opened and handed down by the supervising Server::Starter
+            // process and is guaranteed to be a valid, open TCP listening socket for the
+            // lifetime of this call.
+            let listener = unsafe { std::net::TcpListener::from_raw_fd(fd) };
+            listener.set_nonblocking(true)?;
+            InheritedListener::Tcp(listener)
+        } else {
+            // Safety: as above, but the fd is guaranteed to be a valid, open Unix domain
+            // listening socket instead.
+            let listener = unsafe { std::os::unix::net::UnixListener::from_raw_fd(fd) };
+            listener.set_nonblocking(true)?;
+            InheritedListener::Unix(listener)
+        };
+        listeners.push(listener);
+    }
+
+    Ok(Some(listeners))
+}
+
+/// A single named readiness probe, e.g. "can reach the database".
+type ReadinessCheck = Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>> + Send + Sync>;
+
+/// Registry of readiness probes other parts of the SDK can register at startup. Liveness is
+/// intentionally not modeled here — it's just "is the process running", which `/health/live`
+/// answers without consulting this registry at all.
+struct ReadinessRegistry {
+    checks: Vec<(&'static str, ReadinessCheck)>,
+}
+
+impl ReadinessRegistry {
+    fn new() -> Self {
+        Self { checks: Vec::new() }
+    }
+
+    fn register<F, Fut>(&mut self, name: &'static str, check: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.checks.push((name, Box::new(move || Box::pin(check()))));
+    }
+
+    /// Runs every registered check and returns the names of the ones that failed, along with
+    /// their error message.
+    async fn failing(&self) -> Vec<(&'static str, String)> {
+        let mut failing = Vec::new();
+        for (name, check) in &self.checks {
+            if let Err(err) = check().await {
+                failing.push((*name, err));
+            }
+        }
+        failing
+    }
+}
+
+/// Per-connection metadata stashed in the connection's extensions map at accept time by
+/// `on_connect`; copy over this pattern for peer TLS info or a trace correlation ID.
+struct ConnectionId(u64);
+
+/// Reads the `ConnectionId` that `on_connect` attached to this request's connection and
+/// echoes it back as `X-Connection-Id`, giving every request a stable handle for tracing and
+/// correlation without threading an ID manually through each handler.
+async fn connection_id_header(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let connection_id = req.conn_data::<ConnectionId>().map(|c| c.0);
+    let mut res = next.call(req).await?;
+    if let Some(id) = connection_id {
+        res.headers_mut().insert(
+            header::HeaderName::from_static("x-connection-id"),
+            header::HeaderValue::from_str(&id.to_string()).expect("connection id is valid ASCII"),
+        );
+    }
+    Ok(res)
+}
+
 async fn index() -> impl Responder {
     HttpResponse::Ok().body("Hello, world!")
 }
+
+/// Liveness: 200 with an empty body as long as the process can schedule this handler. No
+/// downstream checks are run here — a degraded dependency should surface via readiness, not
+/// get the whole instance killed by the orchestrator's liveness probe.
+async fn health_live() -> impl Responder {
+    HttpResponse::Ok().finish()
+}
+
+/// Readiness: runs every check in the registry and returns 200 only if all of them pass;
+/// otherwise 503 with a JSON list of the checks that failed, so an orchestrator can gate
+/// traffic until the instance is actually able to serve it.
+async fn health_ready(registry: web::Data<Arc<ReadinessRegistry>>) -> impl Responder {
+    let failing = registry.failing().await;
+    if failing.is_empty() {
+        HttpResponse::Ok().finish()
+    } else {
+        let failing: Vec<_> = failing
+            .into_iter()
+            .map(|(name, err)| serde_json::json!({ "check": name, "error": err }))
+            .collect();
+        HttpResponse::ServiceUnavailable().json(serde_json::json!({ "failing_checks": failing }))
+    }
+}
+
+/// Looks up a user by ID. The `{user_id}` path segment is parsed and validated as a `u64`
+/// before this handler ever runs; a non-numeric segment is rejected with the same structured
+/// 404 as `not_found`/`default_service`, via the `PathConfig` error handler registered on the
+/// app, so there's no manual parsing or bounds-checking to do here.
+async fn get_user(path: web::Path<u64>) -> impl Responder {
+    let user_id = path.into_inner();
+    HttpResponse::Ok().body(format!("User #{user_id}"))
+}
+
+/// Catch-all for any route that doesn't match a registered resource; returns a structured
+/// 404 so clients get a consistent response shape instead of Actix's bare default.
+async fn not_found() -> impl Responder {
+    HttpResponse::NotFound().json(serde_json::json!({
+        "error": "not_found",
+        "message": "the requested resource does not exist",
+    }))
+}
+
+/// Maps a failed `web::Path<T>` extraction (e.g. `/users/abc` against `web::Path<u64>`) onto
+/// the same JSON 404 shape as `not_found`, instead of Actix's default bare-text `PathError`
+/// response, so malformed path params and genuinely unmatched routes look identical to clients.
+fn path_error_handler(err: PathError, _req: &HttpRequest) -> Error {
+    let response = HttpResponse::NotFound().json(serde_json::json!({
+        "error": "not_found",
+        "message": "the requested resource does not exist",
+    }));
+    InternalError::from_response(err, response).into()
+}
 ```
 
 This code sets up a simple Actix-web server that listens on the specified port (or default to 8080) and responds with "Hello, world!" for requests to the root ("/") endpoint.
 
 To run the server, you can set the `PORT` environment variable and execute the binary. The server will start, and you can access it at `http://localhost:PORT/`.
 
-This code includes proper error handling, async/await, logging using `env_logger`, configuration, and graceful shutdown. It follows Rust best practices and is ready for production use.
\ No newline at end of file
+To run it under a Server::Starter-style supervisor instead, set `SERVER_STARTER_PORT` to the inherited `host:port=fd` (TCP, via `HttpServer::listen`) or `path=fd` (Unix domain socket, via `HttpServer::listen_uds`) list; the server adopts those sockets directly rather than binding a fresh one, so a parent process can exec a new child against the same accept socket without dropping connections, and prints each inherited listener's actual address instead of the `PORT`-derived banner used for a plain `bind`. On `SIGTERM`, Actix's own signal handling stops accepting new connections and drains in-flight requests within `SHUTDOWN_TIMEOUT` seconds (default 30) before exiting, which is what lets the supervisor retire the old process cleanly during a hot-deploy.
+
+Every request now passes through a small middleware stack: `middleware::Logger` writes an access-log line per request (override the format with `ACCESS_LOG_FORMAT`, otherwise Actix's default is used), `middleware::Compress` negotiates gzip/brotli response compression against the client's `Accept-Encoding` header (set `ENABLE_COMPRESSION=0` to turn it off without recompiling), and `middleware::DefaultHeaders` stamps an `X-Version` header (from `APP_VERSION`, defaulting to `dev`) onto every response so operators can tell which build answered a request.
+
+`GET /users/{user_id}` demonstrates typed path extraction: Actix parses the `{user_id}` segment into a `u64` via `web::Path<u64>` before `get_user` runs, so a malformed ID (non-numeric, out of range) never reaches handler code. Left to Actix's defaults that rejection would be a bare `text/plain` 404 with a different body shape than the rest of the API, so the app registers a `web::PathConfig` error handler (`path_error_handler`) that maps it onto the same structured JSON 404 as `not_found`. Any request that doesn't match a registered route at all, including unknown methods on `/`, is caught by `default_service` and answered with that same JSON shape (requires the `serde_json` crate), so clients get one consistent 404 contract whether the route didn't exist or the path param didn't parse.
+
+`GET /health/live` and `GET /health/ready` are separate from the application routes and exist for orchestrators rather than clients. Liveness always returns `200` as long as the process is up; readiness runs every probe registered in the `ReadinessRegistry` (here just a placeholder `"self"` check) and only returns `200` once all of them pass, otherwise `503` with a JSON list of the checks that failed. Other parts of the SDK register their own probes against the same registry at startup, so a Kubernetes-style orchestrator can hold traffic back from — or restart — an instance that isn't actually ready to serve it.
+
+`on_connect` runs once per accepted TCP connection, before any request on it is routed, and is where a `ConnectionId` is generated and inserted into that connection's extensions map. The `connection_id_header` middleware reads it back out via `ServiceRequest::conn_data` and copies it onto the response as `X-Connection-Id`, so every request sharing that connection carries the same ID — a foundation for tracing and correlation across the SDK without passing an ID through every handler signature by hand.
+
+This code includes proper error handling, async/await, logging using `env_logger`, configuration, and graceful shutdown. It follows Rust best practices and is ready for production use.